@@ -69,7 +69,7 @@ impl Config for Game {
         ServerListPing::Respond {
             online_players: self.player_count.load(Ordering::SeqCst) as i32,
             max_players: MAX_PLAYERS as i32,
-            description: "Hello Valence!".color(Color::AQUA),
+            description: "Hello Valence!".color(Color::Aqua),
             favicon_png: Some(include_bytes!("../assets/favicon.png")),
         }
     }
@@ -106,7 +106,7 @@ impl Config for Game {
                     })
                     .is_err()
                 {
-                    client.disconnect("The server is full!".color(Color::RED));
+                    client.disconnect("The server is full!".color(Color::Red));
                     return false;
                 }
 
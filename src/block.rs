@@ -0,0 +1,31 @@
+//! Block positions and states.
+
+/// The position of a block in world space.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct BlockPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl BlockPos {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// The state of a single block.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BlockState(u16);
+
+impl BlockState {
+    pub const AIR: BlockState = BlockState(0);
+    pub const BEDROCK: BlockState = BlockState(1);
+    pub const STONE: BlockState = BlockState(2);
+
+    /// Returns `true` if this block stops entities from passing through
+    /// it.
+    pub fn is_solid(self) -> bool {
+        self != Self::AIR
+    }
+}
@@ -0,0 +1,59 @@
+//! Chunk storage for a [`World`](crate::world::World).
+
+use std::collections::HashMap;
+
+use crate::block::{BlockPos, BlockState};
+
+/// The chunks loaded in a single world, keyed by `[x, z]` chunk
+/// coordinates.
+#[derive(Default)]
+pub struct Chunks<S = ()> {
+    chunks: HashMap<[i32; 2], Chunk<S>>,
+}
+
+/// A single loaded chunk.
+pub struct Chunk<S = ()> {
+    pub state: S,
+    blocks: HashMap<BlockPos, BlockState>,
+}
+
+impl<S> Chunks<S> {
+    /// Loads a chunk at `pos`, replacing any chunk already there.
+    pub fn insert(&mut self, pos: [i32; 2], state: S) -> &mut Chunk<S> {
+        self.chunks.insert(
+            pos,
+            Chunk {
+                state,
+                blocks: HashMap::new(),
+            },
+        );
+        self.chunks.get_mut(&pos).expect("just inserted")
+    }
+
+    /// Returns the chunk at `pos`, if loaded.
+    pub fn get(&self, pos: [i32; 2]) -> Option<&Chunk<S>> {
+        self.chunks.get(&pos)
+    }
+
+    /// Sets the block at `pos`. Does nothing if the containing chunk isn't
+    /// loaded.
+    pub fn set_block_state(&mut self, pos: BlockPos, block: BlockState) {
+        if let Some(chunk) = self.chunks.get_mut(&chunk_pos(pos)) {
+            chunk.blocks.insert(pos, block);
+        }
+    }
+
+    /// Returns the block at `pos`, or [`BlockState::AIR`] if the
+    /// containing chunk isn't loaded or the position was never set.
+    pub fn get_block_state(&self, pos: BlockPos) -> BlockState {
+        self.chunks
+            .get(&chunk_pos(pos))
+            .and_then(|chunk| chunk.blocks.get(&pos))
+            .copied()
+            .unwrap_or(BlockState::AIR)
+    }
+}
+
+fn chunk_pos(pos: BlockPos) -> [i32; 2] {
+    [pos.x.div_euclid(16), pos.z.div_euclid(16)]
+}
@@ -0,0 +1,404 @@
+//! Connected clients and the events they produce.
+
+use std::collections::VecDeque;
+
+use vek::Vec3;
+
+use crate::ident::{Ident, IdentError};
+use crate::player_list::PlayerListId;
+use crate::text::Text;
+use crate::world::WorldId;
+
+/// The channel `minecraft:brand` plugin messages are reported on, from
+/// which [`Client::brand`] is populated automatically.
+const BRAND_CHANNEL: &str = "minecraft:brand";
+
+/// A client connected to the server.
+pub struct Client<S = ()> {
+    /// Custom state associated with this client.
+    pub state: S,
+    uuid: u128,
+    username: String,
+    textures: Option<PlayerTextures>,
+    position: Vec3<f64>,
+    yaw: f32,
+    pitch: f32,
+    game_mode: GameMode,
+    view_distance: u8,
+    flat: bool,
+    world: Option<WorldId>,
+    player_list: Option<PlayerListId>,
+    created_this_tick: bool,
+    disconnected: bool,
+    brand: Option<String>,
+    events: VecDeque<ClientEvent>,
+    pending_plugin_messages: Vec<(Ident, Vec<u8>)>,
+}
+
+impl<S> Client<S> {
+    pub(crate) fn new(uuid: u128, username: String, state: S) -> Self {
+        Self {
+            state,
+            uuid,
+            username,
+            textures: None,
+            position: Vec3::zero(),
+            yaw: 0.0,
+            pitch: 0.0,
+            game_mode: GameMode::Survival,
+            view_distance: 10,
+            flat: false,
+            world: None,
+            player_list: None,
+            created_this_tick: true,
+            disconnected: false,
+            brand: None,
+            events: VecDeque::new(),
+            pending_plugin_messages: Vec::new(),
+        }
+    }
+
+    /// The client's UUID.
+    pub fn uuid(&self) -> u128 {
+        self.uuid
+    }
+
+    /// The client's username.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// The client's skin and cape textures, if any.
+    pub fn textures(&self) -> Option<&PlayerTextures> {
+        self.textures.as_ref()
+    }
+
+    /// `true` for a newly created client. `Client` itself never clears
+    /// this flag.
+    pub fn created_this_tick(&self) -> bool {
+        self.created_this_tick
+    }
+
+    /// `true` once the client has disconnected and should be removed.
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected
+    }
+
+    /// Marks the client as disconnected. `reason` is converted into
+    /// [`Text`] and then dropped; it is not stored or sent anywhere.
+    pub fn disconnect(&mut self, reason: impl Into<Text>) {
+        let _ = reason.into();
+        self.disconnected = true;
+    }
+
+    /// Spawns the client into `world`.
+    pub fn spawn(&mut self, world: WorldId) {
+        self.world = Some(world);
+    }
+
+    /// The world this client is currently in.
+    pub fn world(&self) -> Option<WorldId> {
+        self.world
+    }
+
+    /// Teleports the client to `position` with the given look angles.
+    pub fn teleport(&mut self, position: impl Into<Vec3<f64>>, yaw: f32, pitch: f32) {
+        self.position = position.into();
+        self.yaw = yaw;
+        self.pitch = pitch;
+    }
+
+    /// The client's current position.
+    pub fn position(&self) -> Vec3<f64> {
+        self.position
+    }
+
+    /// Sets whether the client sees a superflat sky/horizon.
+    pub fn set_flat(&mut self, flat: bool) {
+        self.flat = flat;
+    }
+
+    /// The client's game mode.
+    pub fn game_mode(&self) -> GameMode {
+        self.game_mode
+    }
+
+    /// Sets the client's game mode.
+    pub fn set_game_mode(&mut self, game_mode: GameMode) {
+        self.game_mode = game_mode;
+    }
+
+    /// Sets the client's view distance, in chunks.
+    pub fn set_view_distance(&mut self, dist: u8) {
+        self.view_distance = dist;
+    }
+
+    /// Sets which player list this client sees.
+    pub fn set_player_list(&mut self, player_list: Option<PlayerListId>) {
+        self.player_list = player_list;
+    }
+
+    /// Returns this client itself. `Client` has no setters for player
+    /// entity metadata (cape, sleeves, pose, etc).
+    pub fn player_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    /// The client-reported brand (e.g. `"vanilla"` or `"fabric"`), learned
+    /// from the automatically handled `minecraft:brand` plugin message.
+    pub fn brand(&self) -> Option<&str> {
+        self.brand.as_deref()
+    }
+
+    /// Sends a plugin message on `channel`.
+    pub fn send_plugin_message(
+        &mut self,
+        channel: impl TryInto<Ident, Error = IdentError>,
+        data: impl Into<Vec<u8>>,
+    ) {
+        match channel.try_into() {
+            Ok(channel) => self.pending_plugin_messages.push((channel, data.into())),
+            Err(err) => log::debug!("dropping outgoing plugin message with bad channel: {err}"),
+        }
+    }
+
+    /// Pops the next queued outgoing plugin message, if any. Drained by
+    /// the packet-writing layer.
+    pub(crate) fn pop_pending_plugin_message(&mut self) -> Option<(Ident, Vec<u8>)> {
+        if self.pending_plugin_messages.is_empty() {
+            None
+        } else {
+            Some(self.pending_plugin_messages.remove(0))
+        }
+    }
+
+    /// Called by the packet-reading layer when a plugin message packet
+    /// arrives from this client. Updates [`Client::brand`] when the
+    /// channel is `minecraft:brand`, then queues the event regardless so
+    /// other channels can still be handled through [`pop_event`](Self::pop_event).
+    pub(crate) fn handle_plugin_message(&mut self, channel: Ident, data: Vec<u8>) {
+        if channel.to_string() == BRAND_CHANNEL {
+            // The brand is a UTF-8 string prefixed by its VarInt length.
+            // We only need the bytes that follow the length prefix here.
+            if let Some(brand) = decode_brand(&data) {
+                self.brand = Some(brand);
+            }
+        }
+
+        self.events
+            .push_back(ClientEvent::PluginMessage { channel, data });
+    }
+
+    /// Queues a client event. Called by the packet-reading layer.
+    pub(crate) fn push_event(&mut self, event: ClientEvent) {
+        self.events.push_back(event);
+    }
+
+    /// Pops the next queued client event.
+    pub fn pop_event(&mut self) -> Option<ClientEvent> {
+        self.events.pop_front()
+    }
+}
+
+/// The maximum number of bytes a 32-bit VarInt can occupy.
+const MAX_VARINT_BYTES: usize = 5;
+
+fn decode_brand(data: &[u8]) -> Option<String> {
+    let mut len = 0usize;
+    let mut shift = 0u32;
+    let mut i = 0usize;
+
+    loop {
+        if i >= MAX_VARINT_BYTES {
+            return None;
+        }
+
+        let byte = *data.get(i)?;
+        len |= ((byte & 0x7f) as usize) << shift;
+        i += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+
+    let bytes = data.get(i..i + len)?;
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+/// An event produced by a client, obtained through [`Client::pop_event`].
+#[derive(Clone, Debug)]
+pub enum ClientEvent {
+    ChatMessage {
+        message: String,
+        timestamp: u64,
+    },
+    SettingsChanged {
+        locale: String,
+        view_distance: u8,
+        chat_mode: ChatMode,
+        chat_colors: bool,
+        displayed_skin_parts: DisplayedSkinParts,
+        main_hand: MainHand,
+    },
+    MovePosition {
+        position: Vec3<f64>,
+        on_ground: bool,
+    },
+    MovePositionAndRotation {
+        position: Vec3<f64>,
+        yaw: f32,
+        pitch: f32,
+        on_ground: bool,
+    },
+    MoveRotation {
+        yaw: f32,
+        pitch: f32,
+        on_ground: bool,
+    },
+    MoveOnGround {
+        on_ground: bool,
+    },
+    MoveVehicle {
+        position: Vec3<f64>,
+        yaw: f32,
+        pitch: f32,
+    },
+    StartSneaking,
+    StopSneaking,
+    StartSprinting,
+    StopSprinting,
+    StartJumpWithHorse {
+        jump_boost: u8,
+    },
+    StopJumpWithHorse,
+    LeaveBed,
+    OpenHorseInventory,
+    StartFlyingWithElytra,
+    ArmSwing(Hand),
+    InteractWithEntity {
+        entity_id: i32,
+        hand: Option<Hand>,
+    },
+    SteerBoat {
+        left_paddle_turning: bool,
+        right_paddle_turning: bool,
+    },
+    Digging {
+        position: crate::block::BlockPos,
+        status: DiggingStatus,
+    },
+    /// A plugin message sent by the client on a custom channel. The
+    /// `minecraft:brand` channel is handled automatically before this
+    /// event is queued; see [`Client::brand`].
+    PluginMessage {
+        channel: Ident,
+        data: Vec<u8>,
+    },
+}
+
+/// Which hand a client used for an action.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Hand {
+    Main,
+    Off,
+}
+
+/// Which hand a client holds their hotbar items in.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MainHand {
+    Left,
+    Right,
+}
+
+/// How a client wants chat messages filtered.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ChatMode {
+    Enabled,
+    CommandsOnly,
+    Hidden,
+}
+
+/// The outcome a client reported for a digging action.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DiggingStatus {
+    Started,
+    Cancelled,
+    Finished,
+}
+
+/// A client's game mode.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameMode {
+    Survival,
+    Creative,
+    Adventure,
+    Spectator,
+}
+
+/// Which skin layers a client has enabled, as reported in their client
+/// settings.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub struct DisplayedSkinParts(u8);
+
+impl DisplayedSkinParts {
+    pub fn cape(self) -> bool {
+        self.0 & 0x01 != 0
+    }
+
+    pub fn jacket(self) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    pub fn left_sleeve(self) -> bool {
+        self.0 & 0x04 != 0
+    }
+
+    pub fn right_sleeve(self) -> bool {
+        self.0 & 0x08 != 0
+    }
+
+    pub fn left_pants_leg(self) -> bool {
+        self.0 & 0x10 != 0
+    }
+
+    pub fn right_pants_leg(self) -> bool {
+        self.0 & 0x20 != 0
+    }
+
+    pub fn hat(self) -> bool {
+        self.0 & 0x40 != 0
+    }
+}
+
+/// A client's skin and cape textures.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PlayerTextures {
+    pub skin_url: String,
+    pub cape_url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_brand_reads_the_length_prefixed_string() {
+        let data = [6, b'f', b'a', b'b', b'r', b'i', b'c'];
+        assert_eq!(decode_brand(&data).as_deref(), Some("fabric"));
+    }
+
+    #[test]
+    fn decode_brand_rejects_a_length_longer_than_the_remaining_data() {
+        let data = [10, b'v', b'a', b'n', b'i', b'l', b'l', b'a'];
+        assert_eq!(decode_brand(&data), None);
+    }
+
+    #[test]
+    fn decode_brand_does_not_panic_on_an_unterminated_varint() {
+        // Every byte has its continuation bit set, so a naive decoder
+        // would keep shifting past the 32-bit VarInt limit and panic.
+        let data = [0x80u8; 10];
+        assert_eq!(decode_brand(&data), None);
+    }
+}
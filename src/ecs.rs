@@ -0,0 +1,412 @@
+//! An entity-component-system, offered as an alternative to driving
+//! gameplay logic from a single [`Config::update`] callback.
+//!
+//! Entities are identified by an index into a slot array plus a
+//! generation counter, so a handle obtained before a slot was freed and
+//! reused can never be mistaken for the entity that now occupies it.
+//! Components are stored in parallel arrays indexed by that same slot
+//! index, and each slot carries a bitmask of the components it currently
+//! has so that [`System`]s can be handed exactly the entities matching
+//! their filter.
+//!
+//! [`Config::update`]: crate::config::Config::update
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+/// The maximum number of distinct component types an [`Ecs`] can track.
+///
+/// Component membership is stored as a single `u64` bitmask, so this is a
+/// hard ceiling rather than a tunable default.
+pub const MAX_COMPONENTS: usize = 64;
+
+/// A handle to an entity owned by an [`Ecs`].
+///
+/// Handles are only ever valid for the generation of the slot they were
+/// created in. Once an entity is despawned and its slot recycled, old
+/// handles pointing at that slot compare unequal to the new one and all
+/// `Ecs` accessors treat them as missing.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct EcsId {
+    index: u32,
+    generation: u32,
+}
+
+/// A bitmask identifying a set of component types.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub struct ComponentMask(u64);
+
+impl ComponentMask {
+    /// The mask containing no components.
+    pub const EMPTY: ComponentMask = ComponentMask(0);
+
+    /// Returns the mask with `id` added to this one.
+    pub fn with(self, id: ComponentId) -> ComponentMask {
+        ComponentMask(self.0 | (1 << id.0))
+    }
+
+    /// Returns `true` if `self` contains every component in `other`.
+    pub fn is_superset_of(self, other: ComponentMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    fn set(&mut self, id: ComponentId, present: bool) {
+        if present {
+            self.0 |= 1 << id.0;
+        } else {
+            self.0 &= !(1 << id.0);
+        }
+    }
+}
+
+/// The bit index assigned to a registered component type.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ComponentId(u8);
+
+/// Implemented for the type-erased parallel arrays backing each
+/// registered component type.
+trait Column: Any {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn ensure_len(&mut self, len: usize);
+    fn clear(&mut self, index: usize);
+}
+
+impl<T: 'static> Column for Vec<Option<T>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn ensure_len(&mut self, len: usize) {
+        if self.len() < len {
+            self.resize_with(len, || None);
+        }
+    }
+
+    fn clear(&mut self, index: usize) {
+        self[index] = None;
+    }
+}
+
+struct Slot {
+    generation: u32,
+    occupied: bool,
+    removed: bool,
+    components: ComponentMask,
+    last_components: ComponentMask,
+}
+
+/// Declares which components a [`System`] wants to operate on.
+///
+/// `Ecs::run_systems` hands the system exactly the entities whose
+/// component mask is a superset of [`filter`](System::filter).
+pub trait System<Cx: ?Sized> {
+    /// The components an entity must have for this system to visit it.
+    fn filter(&self, ecs: &Ecs) -> ComponentMask;
+
+    /// Called once per tick with every entity matching [`filter`](System::filter).
+    fn run(&mut self, ecs: &mut Ecs, entities: &[EcsId], cx: &mut Cx);
+}
+
+/// Owns all entities and components for a single ECS world.
+#[derive(Default)]
+pub struct Ecs {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+    columns: HashMap<TypeId, Box<dyn Column>>,
+    component_ids: HashMap<TypeId, ComponentId>,
+}
+
+impl Ecs {
+    /// Creates an empty ECS with no entities or registered components.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`ComponentId`] for `T`, registering it if this is the
+    /// first time `T` has been used with this `Ecs`.
+    ///
+    /// Panics if more than [`MAX_COMPONENTS`] distinct types are
+    /// registered.
+    pub fn component_id<T: 'static>(&mut self) -> ComponentId {
+        let type_id = TypeId::of::<T>();
+        if let Some(&id) = self.component_ids.get(&type_id) {
+            return id;
+        }
+
+        let id = ComponentId(self.component_ids.len().try_into().unwrap_or_else(|_| {
+            panic!("exceeded MAX_COMPONENTS ({MAX_COMPONENTS}) registered component types")
+        }));
+        assert!(
+            (id.0 as usize) < MAX_COMPONENTS,
+            "exceeded MAX_COMPONENTS ({MAX_COMPONENTS}) registered component types"
+        );
+
+        self.component_ids.insert(type_id, id);
+        self.columns
+            .insert(type_id, Box::new(Vec::<Option<T>>::new()));
+        id
+    }
+
+    /// Builds a [`ComponentMask`] containing just `T`.
+    pub fn mask_of<T: 'static>(&mut self) -> ComponentMask {
+        ComponentMask::EMPTY.with(self.component_id::<T>())
+    }
+
+    /// Spawns a new entity with no components.
+    pub fn spawn(&mut self) -> EcsId {
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index as usize];
+            slot.occupied = true;
+            slot.removed = false;
+            slot.components = ComponentMask::EMPTY;
+            slot.last_components = ComponentMask::EMPTY;
+            EcsId {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            let index = self.slots.len() as u32;
+            self.slots.push(Slot {
+                generation: 0,
+                occupied: true,
+                removed: false,
+                components: ComponentMask::EMPTY,
+                last_components: ComponentMask::EMPTY,
+            });
+            EcsId {
+                index,
+                generation: 0,
+            }
+        }
+    }
+
+    /// Marks `id` for removal.
+    ///
+    /// The entity is excluded from queries immediately, but its slot is
+    /// only returned to the free list (and its generation bumped) once
+    /// [`run_systems`](Self::run_systems) finishes the current tick. This
+    /// keeps handles obtained earlier in the same tick from being silently
+    /// aliased onto a brand new entity.
+    pub fn despawn(&mut self, id: EcsId) {
+        if let Some(slot) = self.slot_mut(id) {
+            slot.removed = true;
+        }
+    }
+
+    /// Returns `true` if `id` refers to a live, non-removed entity.
+    pub fn is_alive(&self, id: EcsId) -> bool {
+        self.slots
+            .get(id.index as usize)
+            .is_some_and(|slot| slot.occupied && !slot.removed && slot.generation == id.generation)
+    }
+
+    /// Inserts or replaces the `T` component on `id`.
+    pub fn insert<T: 'static>(&mut self, id: EcsId, value: T) {
+        let component_id = self.component_id::<T>();
+        if self.slot_mut(id).is_none() {
+            return;
+        }
+
+        let len = self.slots.len();
+        let column = self
+            .columns
+            .get_mut(&TypeId::of::<T>())
+            .expect("component column missing after registration");
+        column.ensure_len(len);
+        column
+            .as_any_mut()
+            .downcast_mut::<Vec<Option<T>>>()
+            .expect("component column type mismatch")[id.index as usize] = Some(value);
+
+        self.slot_mut(id).unwrap().components.set(component_id, true);
+    }
+
+    /// Removes the `T` component from `id`, if present.
+    pub fn remove<T: 'static>(&mut self, id: EcsId) {
+        let component_id = self.component_id::<T>();
+        if self.slot_mut(id).is_none() {
+            return;
+        }
+
+        if let Some(column) = self.columns.get_mut(&TypeId::of::<T>()) {
+            column.clear(id.index as usize);
+        }
+
+        self.slot_mut(id).unwrap().components.set(component_id, false);
+    }
+
+    /// Returns a reference to the `T` component on `id`, if present.
+    pub fn get<T: 'static>(&self, id: EcsId) -> Option<&T> {
+        if !self.is_alive(id) {
+            return None;
+        }
+        self.columns
+            .get(&TypeId::of::<T>())?
+            .as_any()
+            .downcast_ref::<Vec<Option<T>>>()?
+            .get(id.index as usize)?
+            .as_ref()
+    }
+
+    /// Returns a mutable reference to the `T` component on `id`, if
+    /// present.
+    pub fn get_mut<T: 'static>(&mut self, id: EcsId) -> Option<&mut T> {
+        if !self.is_alive(id) {
+            return None;
+        }
+        self.columns
+            .get_mut(&TypeId::of::<T>())?
+            .as_any_mut()
+            .downcast_mut::<Vec<Option<T>>>()?
+            .get_mut(id.index as usize)?
+            .as_mut()
+    }
+
+    /// Returns every live entity whose component mask is a superset of
+    /// `filter`.
+    pub fn query(&self, filter: ComponentMask) -> impl Iterator<Item = EcsId> + '_ {
+        self.slots.iter().enumerate().filter_map(move |(index, slot)| {
+            (slot.occupied && !slot.removed && slot.components.is_superset_of(filter)).then(|| {
+                EcsId {
+                    index: index as u32,
+                    generation: slot.generation,
+                }
+            })
+        })
+    }
+
+    /// Returns `true` if `id` gained or lost any components since the end
+    /// of the previous [`run_systems`](Self::run_systems) call.
+    pub fn components_changed(&self, id: EcsId) -> bool {
+        self.slot_mut_ref(id)
+            .is_some_and(|slot| slot.components != slot.last_components)
+    }
+
+    /// Runs every system in `systems` once, then recycles the slots of any
+    /// entities despawned during the tick.
+    ///
+    /// Recycling only after all systems have run is what makes
+    /// [`despawn`](Self::despawn) safe to call mid-tick: a slot index can't
+    /// be handed back out (with a bumped generation) to a `spawn` call made
+    /// by a later system in the same tick, which would otherwise alias a
+    /// handle still held by an earlier one.
+    pub fn run_systems<Cx: ?Sized>(&mut self, systems: &mut [Box<dyn System<Cx>>], cx: &mut Cx) {
+        for system in systems {
+            let filter = system.filter(self);
+            let matched: Vec<EcsId> = self.query(filter).collect();
+            system.run(self, &matched, cx);
+        }
+
+        for index in 0..self.slots.len() {
+            let slot = &mut self.slots[index];
+            if slot.occupied && slot.removed {
+                slot.occupied = false;
+                slot.generation = slot.generation.wrapping_add(1);
+                slot.components = ComponentMask::EMPTY;
+                self.free.push(index as u32);
+
+                // Clear every column at this index so a later `spawn` that
+                // reuses the slot can't read the previous occupant's data
+                // through `get`/`get_mut` before it inserts its own.
+                for column in self.columns.values_mut() {
+                    column.clear(index);
+                }
+            }
+            slot.last_components = slot.components;
+        }
+    }
+
+    fn slot_mut(&mut self, id: EcsId) -> Option<&mut Slot> {
+        let slot = self.slots.get_mut(id.index as usize)?;
+        (slot.occupied && slot.generation == id.generation).then_some(slot)
+    }
+
+    fn slot_mut_ref(&self, id: EcsId) -> Option<&Slot> {
+        let slot = self.slots.get(id.index as usize)?;
+        (slot.occupied && slot.generation == id.generation).then_some(slot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generation_is_bumped_when_a_freed_slot_is_reused() {
+        let mut ecs = Ecs::new();
+        let a = ecs.spawn();
+        ecs.despawn(a);
+        ecs.run_systems::<()>(&mut [], &mut ());
+
+        let b = ecs.spawn();
+
+        assert_eq!(a.index, b.index);
+        assert_ne!(a.generation, b.generation);
+        assert!(!ecs.is_alive(a));
+        assert!(ecs.is_alive(b));
+    }
+
+    #[test]
+    fn despawn_excludes_from_queries_immediately_but_only_recycles_after_run_systems() {
+        let mut ecs = Ecs::new();
+        let a = ecs.spawn();
+        ecs.insert(a, 1u32);
+        let mask = ecs.mask_of::<u32>();
+
+        ecs.despawn(a);
+        assert_eq!(
+            ecs.query(mask).count(),
+            0,
+            "a despawned entity must vanish from queries at once"
+        );
+
+        // Spawning before `run_systems` recycles slots must not alias `a`'s
+        // slot: an earlier system in the same tick may still be holding
+        // its handle.
+        let b = ecs.spawn();
+        assert_ne!(a.index, b.index);
+
+        ecs.run_systems::<()>(&mut [], &mut ());
+        assert!(!ecs.is_alive(a));
+    }
+
+    #[test]
+    fn query_only_yields_entities_with_every_filtered_component() {
+        let mut ecs = Ecs::new();
+
+        let a = ecs.spawn();
+        ecs.insert(a, 1u32);
+        ecs.insert(a, "one");
+
+        let b = ecs.spawn();
+        ecs.insert(b, 2u32);
+
+        let str_id = ecs.component_id::<&'static str>();
+        let filter = ecs.mask_of::<u32>().with(str_id);
+
+        assert_eq!(ecs.query(filter).collect::<Vec<_>>(), vec![a]);
+    }
+
+    #[test]
+    fn a_recycled_slot_does_not_leak_the_previous_occupant_s_components() {
+        let mut ecs = Ecs::new();
+
+        let a = ecs.spawn();
+        ecs.insert(a, 42u32);
+        ecs.despawn(a);
+        ecs.run_systems::<()>(&mut [], &mut ());
+
+        let b = ecs.spawn();
+        assert_eq!(a.index, b.index, "the slot should have been recycled");
+        assert_eq!(
+            ecs.get::<u32>(b),
+            None,
+            "a freshly spawned entity must not see data left behind by the slot's previous occupant"
+        );
+    }
+}
@@ -0,0 +1,527 @@
+//! Entities: players, mobs, and objects tracked by a
+//! [`World`](crate::world::World).
+
+pub mod types;
+
+use vek::Vec3;
+
+use crate::block::BlockPos;
+use crate::chunk::Chunks;
+use crate::world::WorldId;
+use types::Pose;
+
+/// An identifier for an [`Entity`] managed by `Server::entities`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct EntityId {
+    index: u32,
+    generation: u32,
+}
+
+impl EntityId {
+    pub(crate) fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+}
+
+/// The kind of an entity, determining its tracked data and hitbox.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EntityKind {
+    Player,
+    Cow,
+    Zombie,
+    Item,
+}
+
+impl EntityKind {
+    /// The `(width, height)` of this entity kind's hitbox, in blocks.
+    pub fn dimensions(self) -> (f64, f64) {
+        match self {
+            EntityKind::Player => (0.6, 1.8),
+            EntityKind::Cow => (0.9, 1.4),
+            EntityKind::Zombie => (0.6, 1.95),
+            EntityKind::Item => (0.25, 0.25),
+        }
+    }
+
+    /// The downward acceleration [`Entity::tick_physics`] applies each
+    /// tick, in blocks/tick², unless [`Entity::set_no_gravity`] disabled
+    /// it.
+    pub fn gravity(self) -> f64 {
+        match self {
+            EntityKind::Item => 0.04,
+            EntityKind::Player | EntityKind::Cow | EntityKind::Zombie => 0.08,
+        }
+    }
+
+    /// The fraction of velocity retained each tick after gravity is
+    /// applied and before the entity is moved.
+    pub fn drag(self) -> f64 {
+        match self {
+            EntityKind::Item => 0.98,
+            EntityKind::Player | EntityKind::Cow | EntityKind::Zombie => 0.91,
+        }
+    }
+}
+
+/// An axis-aligned bounding box.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Aabb {
+    pub min: Vec3<f64>,
+    pub max: Vec3<f64>,
+}
+
+impl Aabb {
+    pub fn new(min: Vec3<f64>, max: Vec3<f64>) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns the smallest `t >= 0` such that `origin + direction * t`
+    /// lies inside this box, or `None` if the ray never enters it.
+    pub fn ray_intersect(&self, origin: Vec3<f64>, direction: Vec3<f64>) -> Option<f64> {
+        let mut t_min = 0.0_f64;
+        let mut t_max = f64::INFINITY;
+
+        for axis in 0..3 {
+            let (o, d, lo, hi) = match axis {
+                0 => (origin.x, direction.x, self.min.x, self.max.x),
+                1 => (origin.y, direction.y, self.min.y, self.max.y),
+                _ => (origin.z, direction.z, self.min.z, self.max.z),
+            };
+
+            if d.abs() < f64::EPSILON {
+                if o < lo || o > hi {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv_d = 1.0 / d;
+            let mut t0 = (lo - o) * inv_d;
+            let mut t1 = (hi - o) * inv_d;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        Some(t_min)
+    }
+}
+
+/// An entity tracked by a world: a player, mob, or object other than the
+/// blocks making up the world itself.
+pub struct Entity<S = ()> {
+    pub state: S,
+    kind: EntityKind,
+    world: Option<WorldId>,
+    position: Vec3<f64>,
+    yaw: f32,
+    pitch: f32,
+    head_yaw: f32,
+    on_ground: bool,
+    velocity: Vec3<f64>,
+    no_gravity: bool,
+    data: TrackedData,
+    events: Vec<EntityEvent>,
+}
+
+impl<S> Entity<S> {
+    pub(crate) fn new(kind: EntityKind, state: S) -> Self {
+        Self {
+            state,
+            kind,
+            world: None,
+            position: Vec3::zero(),
+            yaw: 0.0,
+            pitch: 0.0,
+            head_yaw: 0.0,
+            on_ground: false,
+            velocity: Vec3::zero(),
+            no_gravity: false,
+            data: TrackedData::new(kind),
+            events: Vec::new(),
+        }
+    }
+
+    /// This entity's kind.
+    pub fn kind(&self) -> EntityKind {
+        self.kind
+    }
+
+    /// The world this entity is in, if any.
+    pub fn world(&self) -> Option<WorldId> {
+        self.world
+    }
+
+    /// Sets the world this entity is in.
+    pub fn set_world(&mut self, world: WorldId) {
+        self.world = Some(world);
+    }
+
+    /// This entity's position.
+    pub fn position(&self) -> Vec3<f64> {
+        self.position
+    }
+
+    /// Sets this entity's position.
+    pub fn set_position(&mut self, position: impl Into<Vec3<f64>>) {
+        self.position = position.into();
+    }
+
+    pub fn yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    pub fn set_yaw(&mut self, yaw: f32) {
+        self.yaw = yaw;
+    }
+
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    pub fn set_pitch(&mut self, pitch: f32) {
+        self.pitch = pitch;
+    }
+
+    pub fn head_yaw(&self) -> f32 {
+        self.head_yaw
+    }
+
+    pub fn set_head_yaw(&mut self, head_yaw: f32) {
+        self.head_yaw = head_yaw;
+    }
+
+    pub fn on_ground(&self) -> bool {
+        self.on_ground
+    }
+
+    pub fn set_on_ground(&mut self, on_ground: bool) {
+        self.on_ground = on_ground;
+    }
+
+    /// This entity's tracked data, sent to clients so they can render it.
+    pub fn data(&self) -> &TrackedData {
+        &self.data
+    }
+
+    /// A mutable view of this entity's tracked data.
+    pub fn data_mut(&mut self) -> &mut TrackedData {
+        &mut self.data
+    }
+
+    /// Queues a one-shot entity event (e.g. an arm swing animation) to be
+    /// broadcast to nearby clients.
+    pub fn push_event(&mut self, event: EntityEvent) {
+        self.events.push(event);
+    }
+
+    /// This entity's current axis-aligned bounding box, derived from its
+    /// position and [`EntityKind::dimensions`].
+    pub fn bounding_box(&self) -> Aabb {
+        let (width, height) = self.kind.dimensions();
+        let half = width / 2.0;
+        let offset = Vec3::new(half, 0.0, half);
+        Aabb::new(self.position - offset, self.position + Vec3::new(half, height, half))
+    }
+
+    /// This entity's velocity, in blocks/tick.
+    pub fn velocity(&self) -> Vec3<f64> {
+        self.velocity
+    }
+
+    /// Sets this entity's velocity.
+    pub fn set_velocity(&mut self, velocity: impl Into<Vec3<f64>>) {
+        self.velocity = velocity.into();
+    }
+
+    /// Disables (or re-enables) gravity for this entity in
+    /// [`tick_physics`](Self::tick_physics).
+    pub fn set_no_gravity(&mut self, no_gravity: bool) {
+        self.no_gravity = no_gravity;
+    }
+
+    /// Advances this entity by one tick of opt-in server-side physics:
+    /// applies gravity, integrates velocity into position, and resolves
+    /// collisions against solid blocks in `chunks`.
+    ///
+    /// Each axis is swept and resolved independently, so an entity moving
+    /// diagonally into a wall keeps sliding along it instead of stopping
+    /// dead, and landing on a floor only zeroes the vertical component of
+    /// velocity (and sets [`on_ground`](Self::on_ground)) rather than the
+    /// whole vector.
+    pub fn tick_physics(&mut self, chunks: &Chunks) {
+        if !self.no_gravity {
+            self.velocity.y -= self.kind.gravity();
+        }
+
+        let drag = self.kind.drag();
+        self.velocity.x *= drag;
+        self.velocity.y *= drag;
+        self.velocity.z *= drag;
+
+        let (width, height) = self.kind.dimensions();
+        let half = width / 2.0;
+
+        let mut pos = self.position;
+        let mut vel = self.velocity;
+        let mut on_ground = false;
+
+        for axis in 0..3 {
+            let delta = match axis {
+                0 => vel.x,
+                1 => vel.y,
+                _ => vel.z,
+            };
+            if delta == 0.0 {
+                continue;
+            }
+
+            let mut candidate = pos;
+            match axis {
+                0 => candidate.x += delta,
+                1 => candidate.y += delta,
+                _ => candidate.z += delta,
+            }
+
+            let swept = Aabb::new(
+                candidate - Vec3::new(half, 0.0, half),
+                candidate + Vec3::new(half, height, half),
+            );
+
+            if collides_with_solid(&swept, chunks) {
+                if axis == 1 && delta < 0.0 {
+                    on_ground = true;
+                }
+                match axis {
+                    0 => vel.x = 0.0,
+                    1 => vel.y = 0.0,
+                    _ => vel.z = 0.0,
+                }
+            } else {
+                pos = candidate;
+            }
+        }
+
+        self.position = pos;
+        self.velocity = vel;
+
+        // A stationary entity (no vertical velocity this tick, e.g. one
+        // with gravity disabled) never sweeps the y-axis above, so check
+        // directly underneath it here instead of only inferring
+        // `on_ground` from a downward collision during the sweep.
+        let resting = Aabb::new(
+            pos - Vec3::new(half, SUPPORT_CHECK_DISTANCE, half),
+            pos + Vec3::new(half, SUPPORT_CHECK_DISTANCE, half),
+        );
+        self.on_ground = on_ground || collides_with_solid(&resting, chunks);
+    }
+}
+
+/// How far below an entity to probe for a supporting block when checking
+/// [`Entity::on_ground`].
+const SUPPORT_CHECK_DISTANCE: f64 = 0.01;
+
+/// A small inward inset used when testing block overlap so that an AABB
+/// merely touching a block's face (rather than overlapping its volume)
+/// isn't treated as a collision with it.
+const COLLISION_EPSILON: f64 = 1e-7;
+
+/// Returns `true` if any solid block in `chunks` overlaps `aabb`.
+fn collides_with_solid(aabb: &Aabb, chunks: &Chunks) -> bool {
+    let min_x = aabb.min.x.floor() as i32;
+    let max_x = (aabb.max.x - COLLISION_EPSILON).floor() as i32;
+    let min_y = aabb.min.y.floor() as i32;
+    let max_y = (aabb.max.y - COLLISION_EPSILON).floor() as i32;
+    let min_z = aabb.min.z.floor() as i32;
+    let max_z = (aabb.max.z - COLLISION_EPSILON).floor() as i32;
+
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            for z in min_z..=max_z {
+                if chunks.get_block_state(BlockPos::new(x, y, z)).is_solid() {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// The entity-kind-specific data tracked and broadcast to clients.
+#[derive(Clone, Debug)]
+pub enum TrackedData {
+    Player(PlayerEntity),
+    Generic,
+}
+
+impl TrackedData {
+    fn new(kind: EntityKind) -> Self {
+        match kind {
+            EntityKind::Player => TrackedData::Player(PlayerEntity::default()),
+            _ => TrackedData::Generic,
+        }
+    }
+}
+
+/// Tracked data specific to player entities.
+#[derive(Clone, Default, Debug)]
+pub struct PlayerEntity {
+    cape: bool,
+    jacket: bool,
+    left_sleeve: bool,
+    right_sleeve: bool,
+    left_pants_leg: bool,
+    right_pants_leg: bool,
+    hat: bool,
+    main_arm: u8,
+    sprinting: bool,
+    pose: Pose,
+}
+
+impl PlayerEntity {
+    pub fn set_cape(&mut self, cape: bool) {
+        self.cape = cape;
+    }
+
+    pub fn set_jacket(&mut self, jacket: bool) {
+        self.jacket = jacket;
+    }
+
+    pub fn set_left_sleeve(&mut self, left_sleeve: bool) {
+        self.left_sleeve = left_sleeve;
+    }
+
+    pub fn set_right_sleeve(&mut self, right_sleeve: bool) {
+        self.right_sleeve = right_sleeve;
+    }
+
+    pub fn set_left_pants_leg(&mut self, left_pants_leg: bool) {
+        self.left_pants_leg = left_pants_leg;
+    }
+
+    pub fn set_right_pants_leg(&mut self, right_pants_leg: bool) {
+        self.right_pants_leg = right_pants_leg;
+    }
+
+    pub fn set_hat(&mut self, hat: bool) {
+        self.hat = hat;
+    }
+
+    pub fn set_main_arm(&mut self, main_arm: u8) {
+        self.main_arm = main_arm;
+    }
+
+    pub fn set_sprinting(&mut self, sprinting: bool) {
+        self.sprinting = sprinting;
+    }
+
+    pub fn get_pose(&self) -> Pose {
+        self.pose
+    }
+
+    pub fn set_pose(&mut self, pose: Pose) {
+        self.pose = pose;
+    }
+}
+
+/// A one-shot entity event broadcast to nearby clients, such as an
+/// animation.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EntityEvent {
+    SwingMainHand,
+    SwingOffHand,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::BlockState;
+
+    fn floor_at(y: i32) -> Chunks {
+        let mut chunks = Chunks::default();
+        chunks.insert([0, 0], ());
+        for x in -2..=2 {
+            for z in -2..=2 {
+                chunks.set_block_state(BlockPos::new(x, y, z), BlockState::STONE);
+            }
+        }
+        chunks
+    }
+
+    #[test]
+    fn gravity_accelerates_a_falling_entity_downward() {
+        let chunks = Chunks::default();
+        let mut entity = Entity::new(EntityKind::Item, ());
+        entity.set_position(Vec3::new(0.5, 50.0, 0.5));
+
+        entity.tick_physics(&chunks);
+
+        assert!(
+            entity.velocity().y < 0.0,
+            "gravity should add downward velocity"
+        );
+        assert!(
+            entity.position().y < 50.0,
+            "the entity should have started falling"
+        );
+    }
+
+    #[test]
+    fn a_falling_entity_settles_on_a_solid_floor() {
+        let chunks = floor_at(0);
+        let mut entity = Entity::new(EntityKind::Cow, ());
+        entity.set_position(Vec3::new(0.5, 5.0, 0.5));
+
+        for _ in 0..200 {
+            entity.tick_physics(&chunks);
+        }
+
+        assert!(
+            entity.on_ground(),
+            "the entity should have landed on the floor"
+        );
+        assert_eq!(entity.velocity().y, 0.0);
+        assert!(
+            entity.position().y >= 1.0,
+            "the entity shouldn't sink into the floor"
+        );
+    }
+
+    #[test]
+    fn a_stationary_no_gravity_entity_resting_on_a_floor_reports_on_ground() {
+        let chunks = floor_at(0);
+        let mut entity = Entity::new(EntityKind::Item, ());
+        entity.set_no_gravity(true);
+        entity.set_position(Vec3::new(0.5, 1.0, 0.5));
+
+        entity.tick_physics(&chunks);
+
+        assert!(
+            entity.on_ground(),
+            "an entity resting directly on a block should report on_ground even with no velocity"
+        );
+    }
+
+    #[test]
+    fn a_block_touching_the_body_but_not_the_feet_does_not_count_as_on_ground() {
+        let chunks = floor_at(11);
+        let mut entity = Entity::new(EntityKind::Cow, ());
+        entity.set_no_gravity(true);
+        entity.set_position(Vec3::new(0.5, 10.0, 0.5));
+
+        entity.tick_physics(&chunks);
+
+        assert!(
+            !entity.on_ground(),
+            "a block touching the entity's head with nothing under its feet must not set on_ground"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Small enums describing tracked entity state.
+
+/// An entity's pose, as shown to other clients.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum Pose {
+    #[default]
+    Standing,
+    Sneaking,
+    Sleeping,
+    Swimming,
+    Dying,
+}
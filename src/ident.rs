@@ -0,0 +1,138 @@
+//! Minecraft identifiers (`namespace:path`).
+
+use std::error::Error;
+use std::fmt;
+
+/// A validated Minecraft identifier, e.g. `minecraft:brand`.
+///
+/// Consists of a namespace and a path, each restricted to `[a-z0-9_.-]`,
+/// with `/` additionally allowed in the path. A bare path with no `:`
+/// defaults to the `minecraft` namespace.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Ident {
+    namespace: String,
+    path: String,
+}
+
+/// The error returned when a string isn't a valid [`Ident`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IdentError(String);
+
+impl fmt::Display for IdentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid identifier `{}`", self.0)
+    }
+}
+
+impl Error for IdentError {}
+
+impl Ident {
+    /// Parses `s` as an identifier, defaulting to the `minecraft`
+    /// namespace if none is given.
+    pub fn new(s: impl Into<String>) -> Result<Self, IdentError> {
+        let s = s.into();
+
+        let (namespace, path) = match s.split_once(':') {
+            Some((ns, path)) => (ns.to_owned(), path.to_owned()),
+            None => ("minecraft".to_owned(), s.clone()),
+        };
+
+        let valid_ns = !namespace.is_empty()
+            && namespace
+                .bytes()
+                .all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'.' | b'-'));
+        let valid_path = !path.is_empty()
+            && path
+                .bytes()
+                .all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'.' | b'-' | b'/'));
+
+        if valid_ns && valid_path {
+            Ok(Self { namespace, path })
+        } else {
+            Err(IdentError(s))
+        }
+    }
+
+    /// The namespace portion, e.g. `minecraft`.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// The path portion, e.g. `brand`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for Ident {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.path)
+    }
+}
+
+impl TryFrom<&str> for Ident {
+    type Error = IdentError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for Ident {
+    type Error = IdentError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespace_and_path_are_split_on_the_colon() {
+        let ident = Ident::new("minecraft:brand").unwrap();
+        assert_eq!(ident.namespace(), "minecraft");
+        assert_eq!(ident.path(), "brand");
+    }
+
+    #[test]
+    fn a_bare_path_defaults_to_the_minecraft_namespace() {
+        let ident = Ident::new("brand").unwrap();
+        assert_eq!(ident.namespace(), "minecraft");
+        assert_eq!(ident.path(), "brand");
+    }
+
+    #[test]
+    fn a_path_may_contain_slashes() {
+        let ident = Ident::new("minecraft:textures/block/stone.png").unwrap();
+        assert_eq!(ident.path(), "textures/block/stone.png");
+    }
+
+    #[test]
+    fn an_empty_namespace_is_rejected() {
+        assert!(Ident::new(":brand").is_err());
+    }
+
+    #[test]
+    fn an_empty_path_is_rejected() {
+        assert!(Ident::new("minecraft:").is_err());
+    }
+
+    #[test]
+    fn uppercase_characters_are_rejected() {
+        assert!(Ident::new("Minecraft:Brand").is_err());
+    }
+
+    #[test]
+    fn a_slash_in_the_namespace_is_rejected() {
+        assert!(Ident::new("mine/craft:brand").is_err());
+    }
+
+    #[test]
+    fn display_round_trips_through_new() {
+        let ident = Ident::new("minecraft:brand").unwrap();
+        assert_eq!(ident.to_string(), "minecraft:brand");
+    }
+}
@@ -0,0 +1,12 @@
+pub mod block;
+pub mod chunk;
+pub mod client;
+pub mod ecs;
+pub mod entity;
+pub mod ident;
+pub mod player_list;
+pub mod server;
+pub mod text;
+pub mod time;
+pub mod util;
+pub mod world;
@@ -0,0 +1,14 @@
+//! The tab list shown to clients.
+
+/// An identifier for a player list managed by `Server::player_lists`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PlayerListId {
+    index: u32,
+    generation: u32,
+}
+
+impl PlayerListId {
+    pub(crate) fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+}
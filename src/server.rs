@@ -0,0 +1,76 @@
+//! Server-wide state shared across every world and client.
+
+use crate::world::{WorldId, Worlds};
+
+/// State shared across an entire server, independent of any single world
+/// or client.
+pub struct SharedServer {
+    tick_rate: u32,
+    current_tick: i64,
+}
+
+impl SharedServer {
+    pub(crate) fn new(tick_rate: u32) -> Self {
+        Self {
+            tick_rate,
+            current_tick: 0,
+        }
+    }
+
+    /// The number of ticks the server advances per second.
+    pub fn tick_rate(&self) -> u32 {
+        self.tick_rate
+    }
+
+    /// The number of ticks elapsed since the server started.
+    pub fn current_tick(&self) -> i64 {
+        self.current_tick
+    }
+
+    pub(crate) fn tick(&mut self) {
+        self.current_tick += 1;
+    }
+}
+
+/// A server and every world it manages.
+///
+/// `Server::tick` is the per-tick driver: it advances [`SharedServer`] and
+/// every world's clock, then queues each world's Time Update packet fields
+/// for the packet-writing layer to drain with [`pop_time_update`](Self::pop_time_update).
+pub struct Server<W = ()> {
+    pub shared: SharedServer,
+    pub worlds: Worlds<W>,
+    pending_time_updates: Vec<(WorldId, i64, i64)>,
+}
+
+impl<W> Server<W> {
+    pub fn new(tick_rate: u32) -> Self {
+        Self {
+            shared: SharedServer::new(tick_rate),
+            worlds: Worlds::default(),
+            pending_time_updates: Vec::new(),
+        }
+    }
+
+    /// Advances the shared tick counter and every world's clock by one
+    /// tick, queuing each world's `(world_age, time_of_day)` Time Update
+    /// fields for [`pop_time_update`](Self::pop_time_update) to drain.
+    pub fn tick(&mut self) {
+        self.shared.tick();
+
+        for (id, world) in self.worlds.iter_mut() {
+            let (world_age, time_of_day) = world.tick();
+            self.pending_time_updates.push((id, world_age, time_of_day));
+        }
+    }
+
+    /// Pops the next queued Time Update packet for the packet-writing
+    /// layer to broadcast to the named world's clients, if any.
+    pub fn pop_time_update(&mut self) -> Option<(WorldId, i64, i64)> {
+        if self.pending_time_updates.is_empty() {
+            None
+        } else {
+            Some(self.pending_time_updates.remove(0))
+        }
+    }
+}
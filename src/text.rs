@@ -0,0 +1,53 @@
+//! Formatted chat text.
+
+/// A chat component: either a plain string or styled text built up with
+/// [`TextFormat`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Text {
+    content: String,
+    color: Option<Color>,
+}
+
+impl Text {
+    fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            color: None,
+        }
+    }
+}
+
+impl From<&str> for Text {
+    fn from(s: &str) -> Self {
+        Text::new(s)
+    }
+}
+
+impl From<String> for Text {
+    fn from(s: String) -> Self {
+        Text::new(s)
+    }
+}
+
+/// A named text color.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Color {
+    Aqua,
+    Red,
+    Green,
+    Yellow,
+    White,
+    Gray,
+}
+
+/// Adds chat formatting to a value convertible into [`Text`].
+pub trait TextFormat: Into<Text> {
+    /// Sets the text color.
+    fn color(self, color: Color) -> Text {
+        let mut text = self.into();
+        text.color = Some(color);
+        text
+    }
+}
+
+impl<T: Into<Text>> TextFormat for T {}
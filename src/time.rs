@@ -0,0 +1,88 @@
+//! World age and time-of-day tracking for a [`World`](crate::world::World).
+
+/// Tracks how long a world has existed and what time of day it currently
+/// is, mirroring the `world_age` / `world_time` pair a full client
+/// maintains.
+#[derive(Copy, Clone, Debug)]
+pub struct WorldTime {
+    world_age: i64,
+    time_of_day: i64,
+    time_scale: f64,
+    carry: f64,
+}
+
+impl Default for WorldTime {
+    fn default() -> Self {
+        Self {
+            world_age: 0,
+            time_of_day: 0,
+            time_scale: 1.0,
+            carry: 0.0,
+        }
+    }
+}
+
+impl WorldTime {
+    /// Total ticks this world has existed for. Unlike [`time_of_day`],
+    /// this always advances, even while the day/night cycle is frozen.
+    ///
+    /// [`time_of_day`]: Self::time_of_day
+    pub fn world_age(&self) -> i64 {
+        self.world_age
+    }
+
+    /// The current time of day in ticks, in `0..24000`, where `0` is
+    /// sunrise.
+    pub fn time_of_day(&self) -> i64 {
+        self.time_of_day
+    }
+
+    /// How many ticks [`time_of_day`](Self::time_of_day) advances per
+    /// world tick. `1.0` matches vanilla's normal cycle, `0.0` freezes it
+    /// (equivalent to the `doDaylightCycle` gamerule being off), and
+    /// values above `1.0` speed it up.
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Sets the time of day, wrapping into `0..24000`.
+    pub fn set_time_of_day(&mut self, time: i64) {
+        self.time_of_day = time.rem_euclid(24000);
+    }
+
+    /// Sets how fast the day/night cycle advances. Negative scales are
+    /// clamped to `0.0`.
+    pub fn set_time_scale(&mut self, scale: f64) {
+        self.time_scale = scale.max(0.0);
+    }
+
+    /// Returns how far through the day/night cycle this world is, as a
+    /// fraction in `0.0..1.0` where `0.0` is sunrise.
+    pub fn day_fraction(&self) -> f64 {
+        self.time_of_day as f64 / 24000.0
+    }
+
+    /// Advances the clock by one world tick.
+    pub(crate) fn tick(&mut self) {
+        self.world_age += 1;
+
+        self.carry += self.time_scale;
+        let whole = self.carry.trunc() as i64;
+        if whole != 0 {
+            self.time_of_day = (self.time_of_day + whole).rem_euclid(24000);
+            self.carry -= whole as f64;
+        }
+    }
+
+    /// Returns the `(world_age, time_of_day)` pair the Time Update packet
+    /// expects: `time_of_day` is negated while the cycle is frozen, which
+    /// is how vanilla signals `doDaylightCycle false` to the client.
+    pub(crate) fn packet_fields(&self) -> (i64, i64) {
+        let time = if self.time_scale == 0.0 {
+            -self.time_of_day
+        } else {
+            self.time_of_day
+        };
+        (self.world_age, time)
+    }
+}
@@ -0,0 +1,139 @@
+//! A single dimension's worth of chunks and world state.
+
+use crate::chunk::Chunks;
+use crate::time::WorldTime;
+
+/// An identifier for a [`World`] managed by `Server::worlds`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct WorldId {
+    index: u32,
+    generation: u32,
+}
+
+impl WorldId {
+    pub(crate) fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+}
+
+/// The blocks and world time that make up a single dimension.
+///
+/// Obtained through `Server::worlds`.
+pub struct World<S = ()> {
+    pub state: S,
+    pub chunks: Chunks,
+    time: WorldTime,
+}
+
+impl<S> World<S> {
+    pub(crate) fn new(state: S) -> Self {
+        Self {
+            state,
+            chunks: Chunks::default(),
+            time: WorldTime::default(),
+        }
+    }
+
+    /// Total ticks this world has existed for.
+    pub fn world_age(&self) -> i64 {
+        self.time.world_age()
+    }
+
+    /// The current time of day in ticks, in `0..24000`, where `0` is
+    /// sunrise.
+    pub fn time_of_day(&self) -> i64 {
+        self.time.time_of_day()
+    }
+
+    /// Sets the time of day, wrapping into `0..24000`.
+    pub fn set_time_of_day(&mut self, time: i64) {
+        self.time.set_time_of_day(time);
+    }
+
+    /// Sets how fast the day/night cycle advances relative to real ticks.
+    /// `0.0` freezes it.
+    pub fn set_time_scale(&mut self, scale: f64) {
+        self.time.set_time_scale(scale);
+    }
+
+    /// Returns how far through the day/night cycle this world is, as a
+    /// fraction in `0.0..1.0` where `0.0` is sunrise.
+    pub fn day_fraction(&self) -> f64 {
+        self.time.day_fraction()
+    }
+
+    /// Advances this world's clock by one tick and returns the
+    /// `(world_age, time_of_day)` fields for this tick's Time Update
+    /// packet. Called once per server tick by `Server::tick`.
+    pub fn tick(&mut self) -> (i64, i64) {
+        self.time.tick();
+        self.time.packet_fields()
+    }
+}
+
+/// The worlds managed by a [`Server`](crate::server::Server), keyed by
+/// [`WorldId`].
+pub struct Worlds<S = ()> {
+    worlds: Vec<Option<World<S>>>,
+    generations: Vec<u32>,
+    free: Vec<u32>,
+}
+
+impl<S> Default for Worlds<S> {
+    fn default() -> Self {
+        Self {
+            worlds: Vec::new(),
+            generations: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+}
+
+impl<S> Worlds<S> {
+    /// Creates a new world with the given state and adds it to this
+    /// collection.
+    pub fn insert(&mut self, state: S) -> (WorldId, &mut World<S>) {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.worlds.push(None);
+                self.generations.push(0);
+                (self.worlds.len() - 1) as u32
+            }
+        };
+
+        self.worlds[index as usize] = Some(World::new(state));
+        let id = WorldId::new(index, self.generations[index as usize]);
+        (id, self.worlds[index as usize].as_mut().expect("just inserted"))
+    }
+
+    /// Returns the world identified by `id`, if it still exists.
+    pub fn get(&self, id: WorldId) -> Option<&World<S>> {
+        if self.generations.get(id.index as usize).copied() != Some(id.generation) {
+            return None;
+        }
+        self.worlds.get(id.index as usize)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the world identified by `id`, if it
+    /// still exists.
+    pub fn get_mut(&mut self, id: WorldId) -> Option<&mut World<S>> {
+        if self.generations.get(id.index as usize).copied() != Some(id.generation) {
+            return None;
+        }
+        self.worlds.get_mut(id.index as usize)?.as_mut()
+    }
+
+    /// Iterates over every world in this collection along with its id.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (WorldId, &mut World<S>)> {
+        self.worlds
+            .iter_mut()
+            .zip(&self.generations)
+            .enumerate()
+            .filter_map(|(index, (world, &generation))| {
+                world
+                    .as_mut()
+                    .map(|world| (WorldId::new(index as u32, generation), world))
+            })
+    }
+}